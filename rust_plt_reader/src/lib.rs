@@ -1,176 +1,371 @@
 use std::convert::TryInto;
+use std::io::{self, Cursor, Read, Seek, SeekFrom, Write};
 
-pub fn i32u(data: &[u8]) -> u32 {
-    let arr: [u8; 4] = data[0..4].try_into().unwrap();
-    u32::from_le_bytes(arr)
+/// Byte order of a PLT file, selected from the byte-order integer the Tecplot
+/// writer emits (value 1) right after the magic number.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Endian {
+    Little,
+    Big,
 }
 
-pub fn i32s(data: &[u8]) -> i32 {
-    let arr: [u8; 4] = data[0..4].try_into().unwrap();
-    i32::from_le_bytes(arr)
+/// Errors raised while decoding a PLT file. These let callers tell "not a PLT
+/// file" apart from "file truncated mid-title" instead of checking a
+/// `correct: bool` flag or panicking on malformed input.
+#[derive(Debug)]
+pub enum PltError {
+    /// The stream ended while `needed` bytes were still expected; only `got`
+    /// were available.
+    UnexpectedEof { needed: usize, got: usize },
+    /// The magic quad word was not the `#!TDV112` signature, or the byte-order
+    /// integer after it was missing or read as 1 in neither byte order.
+    BadMagic,
+    /// The file-type index did not name one of FULL/GRID/SOLUTION. When
+    /// writing, a header whose file-type name is none of these is reported
+    /// with index -1.
+    InvalidFileType(i16),
+    /// A Tecplot string word carried data outside its low byte.
+    MalformedString,
+    /// A zone's IJK dimensions multiply to an element count that does not fit
+    /// in `usize`.
+    DimensionOverflow { dims: [i32; 3] },
+    /// The header declared a negative number of variables.
+    InvalidVarCount(i32),
+    /// An underlying I/O error from the source or sink that is not an
+    /// end-of-file.
+    Io(io::Error),
 }
 
-pub fn i16s(data: &[u8]) -> i16 {
-    let arr: [u8; 2] = data[0..2].try_into().unwrap();
-    i16::from_le_bytes(arr)
+impl std::fmt::Display for PltError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PltError::UnexpectedEof { needed, got } => {
+                write!(f, "unexpected end of file: needed {needed} bytes, got {got}")
+            }
+            PltError::BadMagic => write!(f, "not a PLT file: bad magic number"),
+            PltError::InvalidFileType(t) => write!(f, "invalid file type index {t}"),
+            PltError::MalformedString => write!(f, "malformed Tecplot string"),
+            PltError::DimensionOverflow { dims } => {
+                write!(f, "zone dimensions {dims:?} overflow the element count")
+            }
+            PltError::InvalidVarCount(n) => write!(f, "invalid variable count {n}"),
+            PltError::Io(e) => write!(f, "i/o error: {e}"),
+        }
+    }
 }
 
-pub fn f32_le(data: &[u8]) -> f32 {
-    let arr: [u8; 4] = data[0..4].try_into().unwrap();
-    f32::from_le_bytes(arr)
-}
+impl std::error::Error for PltError {}
 
-pub fn f64_le(data: &[u8]) -> f64 {
-    let arr: [u8; 8] = data[0..8].try_into().unwrap();
-    f64::from_le_bytes(arr)
+impl From<io::Error> for PltError {
+    fn from(e: io::Error) -> Self {
+        PltError::Io(e)
+    }
 }
 
-#[derive(Debug, PartialEq)]
-pub struct TecStrRes {
-    pub correct: bool,
-    pub ch: Option<char>,
-    pub end: bool,
+/// A byte-order aware wrapper over any `Read + Seek` source. The parsing code
+/// pulls fixed-size buffers through the [`FromReader`] helpers so that large
+/// files can be memory-mapped or streamed rather than loaded into a `&[u8]`.
+#[derive(Debug)]
+pub struct StreamReader<R> {
+    pub inner: R,
+    pub endian: Endian,
 }
 
-pub fn read_tec_str(data: &[u8]) -> TecStrRes {
-    if data.len() != 4 {
-        return TecStrRes { correct: false, ch: None, end: false };
-    }
-    let check = i32u(data);
-    if check != 0 {
-        TecStrRes { correct: true, ch: Some(data[0] as char), end: false }
-    } else {
-        TecStrRes { correct: true, ch: None, end: true }
+impl<R> StreamReader<R> {
+    /// Wrap a source with a known byte order.
+    pub fn new(inner: R, endian: Endian) -> StreamReader<R> {
+        StreamReader { inner, endian }
     }
 }
 
-#[derive(Debug, PartialEq)]
-pub struct QWordRes {
-    pub correct: bool,
-    pub qword: u64,
-    pub i32ul: i32,
-    pub uni_chars: String,
-    pub tec_str: String,
+impl<R: Read> Read for StreamReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
 }
 
-pub fn construct_qword(bytes: &[u8]) -> QWordRes {
-    if bytes.len() < 8 {
-        return QWordRes { correct: false, qword: 0, i32ul: 0, uni_chars: String::new(), tec_str: String::new() };
+impl<R: Seek> Seek for StreamReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.inner.seek(pos)
     }
-    let first = read_tec_str(&bytes[0..4]);
-    let second = read_tec_str(&bytes[4..8]);
-    let mut tec = String::new();
-    if first.correct {
-        if let Some(c) = first.ch {
-            tec.push(c);
+}
+
+/// Fixed-width, byte-order aware reads over a `Read + Seek` source. Each method
+/// pulls exactly as many bytes as the value needs and advances the cursor,
+/// reporting a truncated stream as [`PltError::UnexpectedEof`] rather than
+/// panicking.
+pub trait FromReader: Read + Seek {
+    /// Byte order the primitive decoders dispatch on.
+    fn endianness(&self) -> Endian;
+
+    /// Fill `buf` completely or fail with the exact shortfall.
+    fn fill(&mut self, buf: &mut [u8]) -> Result<(), PltError> {
+        let needed = buf.len();
+        let mut got = 0;
+        while got < needed {
+            match self.read(&mut buf[got..]) {
+                Ok(0) => return Err(PltError::UnexpectedEof { needed, got }),
+                Ok(n) => got += n,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e.into()),
+            }
         }
+        Ok(())
     }
-    if second.correct {
-        if let Some(c) = second.ch {
-            tec.push(c);
-        }
+
+    fn read_u32(&mut self) -> Result<u32, PltError> {
+        let mut b = [0u8; 4];
+        self.fill(&mut b)?;
+        Ok(match self.endianness() {
+            Endian::Little => u32::from_le_bytes(b),
+            Endian::Big => u32::from_be_bytes(b),
+        })
+    }
+
+    fn read_i32(&mut self) -> Result<i32, PltError> {
+        let mut b = [0u8; 4];
+        self.fill(&mut b)?;
+        Ok(match self.endianness() {
+            Endian::Little => i32::from_le_bytes(b),
+            Endian::Big => i32::from_be_bytes(b),
+        })
     }
-    let mut qword: u64 = 0;
-    let mut uni = String::new();
-    for (i, b) in bytes[0..8].iter().enumerate() {
-        let shift = (7 - i) * 8;
-        qword += (*b as u64) << shift;
-        uni.push(*b as char);
+
+    fn read_i16(&mut self) -> Result<i16, PltError> {
+        let mut b = [0u8; 2];
+        self.fill(&mut b)?;
+        Ok(match self.endianness() {
+            Endian::Little => i16::from_le_bytes(b),
+            Endian::Big => i16::from_be_bytes(b),
+        })
+    }
+
+    fn read_f32(&mut self) -> Result<f32, PltError> {
+        let mut b = [0u8; 4];
+        self.fill(&mut b)?;
+        Ok(match self.endianness() {
+            Endian::Little => f32::from_le_bytes(b),
+            Endian::Big => f32::from_be_bytes(b),
+        })
+    }
+
+    fn read_f64(&mut self) -> Result<f64, PltError> {
+        let mut b = [0u8; 8];
+        self.fill(&mut b)?;
+        Ok(match self.endianness() {
+            Endian::Little => f64::from_le_bytes(b),
+            Endian::Big => f64::from_be_bytes(b),
+        })
+    }
+
+    /// Read a null-terminated Tecplot string: one endian-aware 4-byte word per
+    /// character (the character in the low byte), ending at the all-zero
+    /// terminator word.
+    fn read_tec_str(&mut self) -> Result<String, PltError> {
+        let mut s = String::new();
+        loop {
+            let w = self.read_u32()?;
+            if w == 0 {
+                break;
+            }
+            if w & !0xFF != 0 {
+                return Err(PltError::MalformedString);
+            }
+            s.push((w & 0xFF) as u8 as char);
+        }
+        Ok(s)
     }
-    let i32ul = i32s(&bytes[0..4]);
-    QWordRes { correct: true, qword, i32ul, uni_chars: uni, tec_str: tec }
 }
 
-pub fn read_magic_number(bytes: &[u8]) -> QWordRes {
-    if bytes.len() < 8 {
-        return QWordRes { correct: false, qword: 0, i32ul: 0, uni_chars: String::new(), tec_str: String::new() };
+impl<R: Read + Seek> FromReader for StreamReader<R> {
+    fn endianness(&self) -> Endian {
+        self.endian
     }
-    construct_qword(&bytes[0..8])
 }
 
-#[derive(Debug, PartialEq)]
-pub struct TitleRes {
-    pub correct: bool,
-    pub title: String,
-    pub next_byte: usize,
-}
-
-pub fn get_title(bytes: &[u8]) -> TitleRes {
-    let mut title = String::new();
-    let mut title_end = false;
-    let mut counter = 0usize;
-    let mut next_rel_byte = 0usize;
-    while !title_end {
-        let first_rel_byte = counter * 8;
-        let second_rel_byte = first_rel_byte + 4;
-        if second_rel_byte + 4 > bytes.len() {
-            return TitleRes { correct: false, title: title, next_byte: next_rel_byte };
-        }
-        let first = read_tec_str(&bytes[first_rel_byte..first_rel_byte + 4]);
-        let second = read_tec_str(&bytes[second_rel_byte..second_rel_byte + 4]);
-        if !first.correct || !second.correct {
-            return TitleRes { correct: false, title: title, next_byte: next_rel_byte };
-        }
-        if first.end {
-            title_end = true;
-            next_rel_byte = first_rel_byte + 4;
-            continue;
-        }
-        if let Some(c) = first.ch { title.push(c); }
-        if second.end {
-            title_end = true;
-            next_rel_byte = second_rel_byte + 4;
-            continue;
+/// A `Read + Seek` view onto the window `[start, end)` of an underlying source,
+/// used to parse the header region or a single zone independently without
+/// re-reading the whole file. Seeks are relative to `start`.
+#[derive(Debug)]
+pub struct BoundedReader<'r, R> {
+    inner: &'r mut R,
+    start: u64,
+    end: u64,
+}
+
+impl<'r, R: Read + Seek> Read for BoundedReader<'r, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let pos = self.inner.stream_position()?;
+        if pos >= self.end {
+            return Ok(0);
         }
-        if let Some(c) = second.ch { title.push(c); }
-        counter += 1;
+        let remaining = (self.end - pos) as usize;
+        let take = buf.len().min(remaining);
+        self.inner.read(&mut buf[..take])
     }
-    TitleRes { correct: true, title, next_byte: next_rel_byte }
 }
 
-pub fn read_var_names(bytes: &[u8], num_vars: i32) -> (Vec<String>, usize) {
-    let mut names = Vec::new();
-    let mut next = 0usize;
-    for _ in 0..num_vars {
-        let res = get_title(&bytes[next..]);
-        if !res.correct {
-            break;
-        }
-        names.push(res.title);
-        next += res.next_byte;
+impl<'r, R: Read + Seek> Seek for BoundedReader<'r, R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let abs = match pos {
+            SeekFrom::Start(o) => self.start + o,
+            SeekFrom::End(o) => (self.end as i64 + o) as u64,
+            SeekFrom::Current(o) => (self.inner.stream_position()? as i64 + o) as u64,
+        };
+        let new_abs = self.inner.seek(SeekFrom::Start(abs))?;
+        Ok(new_abs - self.start)
     }
-    (names, next)
 }
 
-pub fn find_end_of_header(bytes: &[u8]) -> usize {
-    let mut counter = 0usize;
-    while counter * 4 + 4 <= bytes.len() {
-        let value = f32_le(&bytes[counter*4..counter*4+4]);
-        if (value - 357.0).abs() < f32::EPSILON {
-            return counter*4 + 4;
+impl<R: Read + Seek> StreamReader<R> {
+    /// Build a sub-reader bounded to the next `len` bytes from the current
+    /// position, carrying the same byte order. The parent cursor is borrowed
+    /// for the sub-reader's lifetime.
+    pub fn take_seek(&mut self, len: u64) -> Result<StreamReader<BoundedReader<'_, R>>, PltError> {
+        let start = self.inner.stream_position()?;
+        Ok(StreamReader {
+            inner: BoundedReader { inner: &mut self.inner, start, end: start + len },
+            endian: self.endian,
+        })
+    }
+
+    /// Read the 8-byte magic/quad word at the current position.
+    pub fn construct_qword(&mut self) -> Result<QWordRes, PltError> {
+        let mut b = [0u8; 8];
+        self.fill(&mut b)?;
+        let mut qword: u64 = 0;
+        let mut uni = String::new();
+        for (i, x) in b.iter().enumerate() {
+            qword += (*x as u64) << ((7 - i) * 8);
+            uni.push(*x as char);
+        }
+        let mut tec = String::new();
+        if b[0..4] != [0, 0, 0, 0] {
+            tec.push(b[0] as char);
         }
-        counter += 1;
+        if b[4..8] != [0, 0, 0, 0] {
+            tec.push(b[4] as char);
+        }
+        let first: [u8; 4] = b[0..4].try_into().unwrap();
+        let i32ul = match self.endian {
+            Endian::Little => i32::from_le_bytes(first),
+            Endian::Big => i32::from_be_bytes(first),
+        };
+        Ok(QWordRes { qword, i32ul, uni_chars: uni, tec_str: tec })
+    }
+
+    /// Read the magic number quad word from the start of the stream.
+    pub fn read_magic_number(&mut self) -> Result<QWordRes, PltError> {
+        self.seek(SeekFrom::Start(0))?;
+        self.construct_qword()
+    }
+
+    /// Decode an ordered zone's header record, positioned at its `299.0`
+    /// marker, returning a [`Zone`] with every scalar field of the record
+    /// populated (the range and variable arrays are filled later from the data
+    /// section). Assumes nodal value location and no face-neighbour/auxiliary
+    /// data, the layout the writer emits.
+    pub fn read_zone_dims(&mut self) -> Result<Zone, PltError> {
+        let _marker = self.read_f32()?;
+        let name = self.read_tec_str()?;
+        let parent = self.read_i32()?;
+        let strand = self.read_i32()?;
+        let solution_time = self.read_f64()?;
+        let not_used = self.read_i32()?;
+        let zone_type = self.read_i32()?;
+        let var_location = self.read_i32()?;
+        let raw_face = self.read_i32()?;
+        let num_face = self.read_i32()?;
+        let i = self.read_i32()?;
+        let j = self.read_i32()?;
+        let k = self.read_i32()?;
+        Ok(Zone {
+            name,
+            parent,
+            strand,
+            solution_time,
+            not_used,
+            zone_type,
+            var_location,
+            raw_face,
+            num_face,
+            dims: [i, j, k],
+            var_min: Vec::new(),
+            var_max: Vec::new(),
+            data: Vec::new(),
+        })
+    }
+
+    /// Read `count` elements of the given `data_format` from the current
+    /// position into a [`VarData`].
+    pub fn read_var_array(&mut self, data_format: i32, count: usize) -> Result<VarData, PltError> {
+        Ok(match data_format {
+            1 => {
+                let mut v = Vec::with_capacity(count);
+                for _ in 0..count {
+                    v.push(self.read_f32()?);
+                }
+                VarData::F32(v)
+            }
+            2 => {
+                let mut v = Vec::with_capacity(count);
+                for _ in 0..count {
+                    v.push(self.read_f64()?);
+                }
+                VarData::F64(v)
+            }
+            3 => {
+                let mut v = Vec::with_capacity(count);
+                for _ in 0..count {
+                    v.push(self.read_i32()?);
+                }
+                VarData::I32(v)
+            }
+            4 => {
+                let mut v = Vec::with_capacity(count);
+                for _ in 0..count {
+                    v.push(self.read_i16()?);
+                }
+                VarData::I16(v)
+            }
+            5 => {
+                let mut v = vec![0u8; count];
+                self.fill(&mut v)?;
+                VarData::U8(v)
+            }
+            _ => VarData::U8(Vec::new()),
+        })
     }
-    bytes.len()
 }
 
-pub fn find_zones(bytes: &[u8], eo_header: usize) -> Vec<usize> {
-    let mut result = Vec::new();
-    let mut counter = 0usize;
-    while counter * 4 + 4 <= eo_header {
-        let value = f32_le(&bytes[counter*4..counter*4+4]);
-        if (value - 299.0).abs() < f32::EPSILON {
-            result.push(counter*4);
-        }
-        counter += 1;
+/// Read the byte-order integer (the 4 bytes after the magic number) both ways
+/// and return the endianness under which it equals 1, or `None` if neither.
+pub fn detect_endian(data: &[u8]) -> Option<Endian> {
+    if data.len() < 12 {
+        return None;
+    }
+    let arr: [u8; 4] = data[8..12].try_into().ok()?;
+    if i32::from_le_bytes(arr) == 1 {
+        Some(Endian::Little)
+    } else if i32::from_be_bytes(arr) == 1 {
+        Some(Endian::Big)
+    } else {
+        None
     }
-    result
+}
+
+#[derive(Debug, PartialEq)]
+pub struct QWordRes {
+    pub qword: u64,
+    pub i32ul: i32,
+    pub uni_chars: String,
+    pub tec_str: String,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct Header {
-    pub correct: bool,
     pub magic_num: QWordRes,
+    pub endian: Endian,
     pub byte_order: i16,
     pub file_type: String,
     pub title: String,
@@ -180,85 +375,603 @@ pub struct Header {
     pub zone_markers: Vec<usize>,
 }
 
-pub fn read_header(bytes: &[u8]) -> Header {
+/// Raw values of a single variable within a zone, in the element type declared
+/// by that variable's `data_format`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum VarData {
+    F32(Vec<f32>),
+    F64(Vec<f64>),
+    I32(Vec<i32>),
+    I16(Vec<i16>),
+    U8(Vec<u8>),
+}
+
+/// A single zone decoded from the data section: its name, the scalar fields of
+/// its header record (preserved so the zone can be written back unchanged), its
+/// IJK dimensions, the per-variable min/max range, and the raw variable arrays.
+///
+/// Only ordered, nodal zones without passive or shared variables are fully
+/// represented; for those a read/write cycle is byte-for-byte lossless when
+/// the data record carries 0 for the has-passive and has-sharing flags and -1
+/// for the shared-connectivity zone, the values the writer always emits (other
+/// values there are not kept). Passive and shared variables are not modelled —
+/// such zones decode what data they carry but do not round-trip.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Zone {
+    pub name: String,
+    pub parent: i32,
+    pub strand: i32,
+    pub solution_time: f64,
+    pub not_used: i32,
+    pub zone_type: i32,
+    pub var_location: i32,
+    pub raw_face: i32,
+    pub num_face: i32,
+    pub dims: [i32; 3],
+    pub var_min: Vec<f64>,
+    pub var_max: Vec<f64>,
+    pub data: Vec<VarData>,
+}
+
+/// Parse the header from any seekable source whose byte order is already set on
+/// the reader.
+pub fn read_header_from<R: Read + Seek>(
+    r: &mut StreamReader<R>,
+    endian: Endian,
+) -> Result<Header, PltError> {
     let file_type_name = ["FULL", "GRID", "SOLUTION"];
-    let magic_num = read_magic_number(&bytes[0..8]);
-    if !magic_num.correct {
-        return Header {
-            correct: false,
-            magic_num,
-            byte_order: 0,
-            file_type: String::new(),
-            title: String::new(),
-            num_vars: 0,
-            var_names: Vec::new(),
-            eof_header: 0,
-            zone_markers: Vec::new(),
-        };
+    let magic_num = r.read_magic_number()?;
+    if magic_num.uni_chars != "#!TDV112" {
+        return Err(PltError::BadMagic);
     }
-    let byte_order = i16s(&bytes[8..10]);
-    let file_type_idx = i16s(&bytes[12..14]);
-
-    let title_res = get_title(&bytes[16..]);
-    let title = if title_res.correct { title_res.title.clone() } else { String::new() };
-    let num_vars = i32s(&bytes[title_res.next_byte + 16..title_res.next_byte + 20]);
-    let start = title_res.next_byte + 20;
-    let (var_names, next_byte) = read_var_names(&bytes[start..], num_vars);
-    let start_after_vars = start + next_byte;
-    let end_of_header = find_end_of_header(&bytes[start_after_vars..]);
-    let eof_abs = start_after_vars + end_of_header;
-    let zone_markers = find_zones(&bytes[start_after_vars..], end_of_header);
-
-    Header {
-        correct: true,
+    let byte_order = r.read_i32()? as i16;
+    let file_type_idx = r.read_i32()?;
+    let file_type = file_type_name
+        .get(file_type_idx as usize)
+        .ok_or(PltError::InvalidFileType(file_type_idx as i16))?
+        .to_string();
+    let title = r.read_tec_str()?;
+    let num_vars = r.read_i32()?;
+    if num_vars < 0 {
+        return Err(PltError::InvalidVarCount(num_vars));
+    }
+    let mut var_names = Vec::with_capacity(num_vars as usize);
+    for _ in 0..num_vars {
+        match r.read_tec_str() {
+            Ok(s) => var_names.push(s),
+            Err(PltError::UnexpectedEof { .. }) => break,
+            Err(e) => return Err(e),
+        }
+    }
+
+    let mut zone_markers = Vec::new();
+    let eof_header;
+    loop {
+        let pos = r.stream_position()? as usize;
+        match r.read_f32() {
+            Ok(v) => {
+                if (v - 299.0).abs() < f32::EPSILON {
+                    zone_markers.push(pos);
+                }
+                if (v - 357.0).abs() < f32::EPSILON {
+                    eof_header = pos + 4;
+                    break;
+                }
+            }
+            Err(PltError::UnexpectedEof { .. }) => {
+                eof_header = pos;
+                break;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(Header {
         magic_num,
+        endian,
         byte_order,
-        file_type: file_type_name[file_type_idx as usize].to_string(),
+        file_type,
         title,
         num_vars,
         var_names,
-        eof_header: eof_abs,
+        eof_header,
         zone_markers,
+    })
+}
+
+/// Parse the data section from any seekable source, seeking to each zone's
+/// header record for its dimensions.
+pub fn read_data_from<R: Read + Seek>(
+    r: &mut StreamReader<R>,
+    header: &Header,
+) -> Result<Vec<Zone>, PltError> {
+    let num_vars = header.num_vars as usize;
+    let mut zones = Vec::with_capacity(header.zone_markers.len());
+    r.seek(SeekFrom::Start(header.eof_header as u64))?;
+    for &marker_off in header.zone_markers.iter() {
+        let cursor = r.stream_position()?;
+        r.seek(SeekFrom::Start(marker_off as u64))?;
+        let mut zone = r.read_zone_dims()?;
+        let dims = zone.dims;
+        r.seek(SeekFrom::Start(cursor))?;
+
+        let _marker = r.read_f32()?; // 299.0 zone marker
+        let mut formats = Vec::with_capacity(num_vars);
+        for _ in 0..num_vars {
+            formats.push(r.read_i32()?);
+        }
+
+        let mut passive = vec![false; num_vars];
+        if r.read_i32()? != 0 {
+            for slot in passive.iter_mut() {
+                *slot = r.read_i32()? != 0;
+            }
+        }
+
+        let mut shared = vec![-1i32; num_vars];
+        if r.read_i32()? != 0 {
+            for slot in shared.iter_mut() {
+                *slot = r.read_i32()?;
+            }
+        }
+
+        let _shared_conn = r.read_i32()?;
+
+        for v in 0..num_vars {
+            if passive[v] || shared[v] != -1 {
+                continue;
+            }
+            zone.var_min.push(r.read_f64()?);
+            zone.var_max.push(r.read_f64()?);
+        }
+
+        let count = (dims[0].max(1) as usize)
+            .checked_mul(dims[1].max(1) as usize)
+            .and_then(|n| n.checked_mul(dims[2].max(1) as usize))
+            .ok_or(PltError::DimensionOverflow { dims })?;
+        for v in 0..num_vars {
+            if passive[v] || shared[v] != -1 {
+                continue;
+            }
+            zone.data.push(r.read_var_array(formats[v], count)?);
+        }
+
+        zones.push(zone);
+    }
+    Ok(zones)
+}
+
+/// Parse a PLT header from an in-memory buffer. Thin adapter over
+/// [`read_header_from`] via a [`Cursor`].
+pub fn read_header(bytes: &[u8]) -> Result<Header, PltError> {
+    let endian = detect_endian(bytes).ok_or(PltError::BadMagic)?;
+    let mut r = StreamReader::new(Cursor::new(bytes), endian);
+    read_header_from(&mut r, endian)
+}
+
+/// Parse the data section from an in-memory buffer. Thin adapter over
+/// [`read_data_from`] via a [`Cursor`].
+pub fn read_data(bytes: &[u8], header: &Header) -> Result<Vec<Zone>, PltError> {
+    let mut r = StreamReader::new(Cursor::new(bytes), header.endian);
+    read_data_from(&mut r, header)
+}
+
+/// A byte-order aware wrapper over any `Write` sink, the mirror of
+/// [`StreamReader`]. The encoding code pushes fixed-size values through the
+/// [`ToWriter`] helpers so that a parsed header and its zones can be emitted
+/// back out in the same `Endian` they were read in.
+#[derive(Debug)]
+pub struct StreamWriter<W> {
+    pub inner: W,
+    pub endian: Endian,
+}
+
+impl<W> StreamWriter<W> {
+    /// Wrap a sink with a known byte order.
+    pub fn new(inner: W, endian: Endian) -> StreamWriter<W> {
+        StreamWriter { inner, endian }
+    }
+}
+
+impl<W: Write> Write for StreamWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write(buf)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Fixed-width, byte-order aware writes, the exact inverse of the
+/// [`FromReader`] decoders. Each method emits a value in the sink's byte order.
+pub trait ToWriter: Write {
+    /// Byte order the primitive encoders dispatch on.
+    fn endianness(&self) -> Endian;
+
+    fn write_u32(&mut self, v: u32) -> Result<(), PltError> {
+        let b = match self.endianness() {
+            Endian::Little => v.to_le_bytes(),
+            Endian::Big => v.to_be_bytes(),
+        };
+        self.write_all(&b)?;
+        Ok(())
+    }
+
+    fn write_i32(&mut self, v: i32) -> Result<(), PltError> {
+        let b = match self.endianness() {
+            Endian::Little => v.to_le_bytes(),
+            Endian::Big => v.to_be_bytes(),
+        };
+        self.write_all(&b)?;
+        Ok(())
+    }
+
+    fn write_i16(&mut self, v: i16) -> Result<(), PltError> {
+        let b = match self.endianness() {
+            Endian::Little => v.to_le_bytes(),
+            Endian::Big => v.to_be_bytes(),
+        };
+        self.write_all(&b)?;
+        Ok(())
+    }
+
+    fn write_f32(&mut self, v: f32) -> Result<(), PltError> {
+        let b = match self.endianness() {
+            Endian::Little => v.to_le_bytes(),
+            Endian::Big => v.to_be_bytes(),
+        };
+        self.write_all(&b)?;
+        Ok(())
+    }
+
+    fn write_f64(&mut self, v: f64) -> Result<(), PltError> {
+        let b = match self.endianness() {
+            Endian::Little => v.to_le_bytes(),
+            Endian::Big => v.to_be_bytes(),
+        };
+        self.write_all(&b)?;
+        Ok(())
+    }
+
+    /// Emit a Tecplot string: one 4-byte word per character (the character in
+    /// the low byte) followed by the all-zero terminator word.
+    fn write_tec_str(&mut self, s: &str) -> Result<(), PltError> {
+        for c in s.chars() {
+            self.write_i32(c as i32)?;
+        }
+        self.write_i32(0)?;
+        Ok(())
     }
 }
 
+impl<W: Write> ToWriter for StreamWriter<W> {
+    fn endianness(&self) -> Endian {
+        self.endian
+    }
+}
+
+impl<W: Write> StreamWriter<W> {
+    /// Emit one zone's header record: the `299.0` marker, the zone name, the
+    /// preserved scalar fields, and the IJK dimensions — the exact inverse of
+    /// [`StreamReader::read_zone_dims`].
+    fn write_zone_record(&mut self, zone: &Zone) -> Result<(), PltError> {
+        self.write_f32(299.0)?;
+        self.write_tec_str(&zone.name)?;
+        self.write_i32(zone.parent)?;
+        self.write_i32(zone.strand)?;
+        self.write_f64(zone.solution_time)?;
+        self.write_i32(zone.not_used)?;
+        self.write_i32(zone.zone_type)?;
+        self.write_i32(zone.var_location)?;
+        self.write_i32(zone.raw_face)?;
+        self.write_i32(zone.num_face)?;
+        self.write_i32(zone.dims[0])?;
+        self.write_i32(zone.dims[1])?;
+        self.write_i32(zone.dims[2])?;
+        Ok(())
+    }
+
+    /// Emit one zone's data record: the `299.0` marker, the per-variable
+    /// formats, passive/sharing flags, the min/max ranges, and the raw arrays.
+    /// All variables are treated as active and unshared: both flags are written
+    /// as 0 and the shared-connectivity zone as -1, whatever was read.
+    fn write_data_record(&mut self, zone: &Zone) -> Result<(), PltError> {
+        self.write_f32(299.0)?;
+        for var in zone.data.iter() {
+            self.write_i32(var.data_format())?;
+        }
+        self.write_i32(0)?; // has passive
+        self.write_i32(0)?; // has var sharing
+        self.write_i32(-1)?; // shared connectivity
+        for (min, max) in zone.var_min.iter().zip(zone.var_max.iter()) {
+            self.write_f64(*min)?;
+            self.write_f64(*max)?;
+        }
+        for var in zone.data.iter() {
+            match var {
+                VarData::F32(v) => {
+                    for x in v {
+                        self.write_f32(*x)?;
+                    }
+                }
+                VarData::F64(v) => {
+                    for x in v {
+                        self.write_f64(*x)?;
+                    }
+                }
+                VarData::I32(v) => {
+                    for x in v {
+                        self.write_i32(*x)?;
+                    }
+                }
+                VarData::I16(v) => {
+                    for x in v {
+                        self.write_i16(*x)?;
+                    }
+                }
+                VarData::U8(v) => self.write_all(v)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl VarData {
+    /// The `data_format` integer the reader uses to select this element type.
+    fn data_format(&self) -> i32 {
+        match self {
+            VarData::F32(_) => 1,
+            VarData::F64(_) => 2,
+            VarData::I32(_) => 3,
+            VarData::I16(_) => 4,
+            VarData::U8(_) => 5,
+        }
+    }
+}
+
+/// Encode `header` and `zones` as a TDV112 PLT file into `w`, the inverse of
+/// [`read_header`]/[`read_data`]. The file is written in `header.endian`; for
+/// the ordered, nodal zones without passive or shared variables that [`Zone`]
+/// fully represents, a file read with [`read_header`]/[`read_data`] round-trips
+/// byte-for-byte when its data records hold the flag values the writer emits
+/// (see [`Zone`]).
+pub fn write_plt<W: Write>(w: W, header: &Header, zones: &[Zone]) -> Result<(), PltError> {
+    let file_type_name = ["FULL", "GRID", "SOLUTION"];
+    let file_type_idx = file_type_name
+        .iter()
+        .position(|&n| n == header.file_type)
+        .ok_or(PltError::InvalidFileType(-1))? as i32;
+
+    let mut w = StreamWriter::new(w, header.endian);
+    w.write_all(b"#!TDV112")?;
+    w.write_i32(1)?; // byte order
+    w.write_i32(file_type_idx)?;
+    w.write_tec_str(&header.title)?;
+    w.write_i32(header.num_vars)?;
+    for name in header.var_names.iter() {
+        w.write_tec_str(name)?;
+    }
+    for zone in zones {
+        w.write_zone_record(zone)?;
+    }
+    w.write_f32(357.0)?; // EOHMARKER
+    for zone in zones {
+        w.write_data_record(zone)?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn reader(bytes: &[u8]) -> StreamReader<Cursor<&[u8]>> {
+        StreamReader::new(Cursor::new(bytes), Endian::Little)
+    }
+
     #[test]
     fn test_construct_qword() {
         let bytes = b"\x01\x00\x00\x00\x00\x00\x00\x01";
-        let res = construct_qword(bytes);
-        assert!(res.correct);
+        let res = reader(bytes).construct_qword().unwrap();
         assert_eq!(res.qword, 72057594037927937u64);
     }
 
     #[test]
     fn test_construct_qword_tecstr() {
         let bytes = b"\x2e\x00\x00\x00\x2e\x00\x00\x00";
-        let res = construct_qword(bytes);
-        assert!(res.correct);
+        let res = reader(bytes).construct_qword().unwrap();
         assert_eq!(res.tec_str, "..");
     }
 
     #[test]
     fn test_read_magic_number() {
         let bytes = b"\x23\x21\x54\x44\x56\x31\x31\x32";
-        let res = read_magic_number(bytes);
-        assert!(res.correct);
+        let res = reader(bytes).read_magic_number().unwrap();
         assert_eq!(res.uni_chars, "#!TDV112");
     }
 
     #[test]
     fn test_read_header() {
         let data = b"\x23\x21\x54\x44\x56\x31\x31\x32\x01\x00\x00\x00\x00\x00\x00\x00\x2e\x00\x00\x00\x2e\x00\x00\x00\x2e\x00\x00\x00\x00\x00\x00\x00\x2f\x00\x00\x00\x50\x00\x00\x00\x69\x00\x00\x00\x63\x00\x00\x00\x74\x00\x00\x00\x75\x00\x00\x00\x72\x00\x00\x00\x65\x00\x00\x00\x00\x00\x00\x00\x78\x00\x00\x00";
-        let hdr = read_header(data);
-        assert!(hdr.correct);
+        let hdr = read_header(data).unwrap();
         assert_eq!(hdr.magic_num.uni_chars, "#!TDV112");
+        assert_eq!(hdr.endian, Endian::Little);
         assert_eq!(hdr.byte_order, 1);
         assert_eq!(hdr.file_type, "FULL");
         assert_eq!(hdr.num_vars, 47);
         assert_eq!(hdr.title, "...");
     }
+
+    #[test]
+    fn test_read_data() {
+        let mut f: Vec<u8> = Vec::new();
+        f.extend_from_slice(b"#!TDV112");
+        f.extend_from_slice(&1i32.to_le_bytes()); // byte order
+        f.extend_from_slice(&0i32.to_le_bytes()); // file type FULL
+        f.extend_from_slice(&(b'x' as i32).to_le_bytes()); // title "x"
+        f.extend_from_slice(&0i32.to_le_bytes());
+        f.extend_from_slice(&1i32.to_le_bytes()); // num vars
+        f.extend_from_slice(&(b'V' as i32).to_le_bytes()); // var name "V"
+        f.extend_from_slice(&0i32.to_le_bytes());
+        // zone header record
+        f.extend_from_slice(&299.0f32.to_le_bytes());
+        f.extend_from_slice(&(b'Z' as i32).to_le_bytes()); // zone name "Z"
+        f.extend_from_slice(&0i32.to_le_bytes());
+        f.extend_from_slice(&(-1i32).to_le_bytes()); // parent zone
+        f.extend_from_slice(&(-1i32).to_le_bytes()); // strand id
+        f.extend_from_slice(&0f64.to_le_bytes()); // solution time
+        f.extend_from_slice(&(-1i32).to_le_bytes()); // not used
+        f.extend_from_slice(&0i32.to_le_bytes()); // zone type
+        f.extend_from_slice(&0i32.to_le_bytes()); // var location
+        f.extend_from_slice(&0i32.to_le_bytes()); // raw face neighbours
+        f.extend_from_slice(&0i32.to_le_bytes()); // user face connections
+        f.extend_from_slice(&2i32.to_le_bytes()); // IMax
+        f.extend_from_slice(&1i32.to_le_bytes()); // JMax
+        f.extend_from_slice(&1i32.to_le_bytes()); // KMax
+        f.extend_from_slice(&357.0f32.to_le_bytes()); // end of header
+        // data section
+        f.extend_from_slice(&299.0f32.to_le_bytes());
+        f.extend_from_slice(&1i32.to_le_bytes()); // data format f32
+        f.extend_from_slice(&0i32.to_le_bytes()); // has passive
+        f.extend_from_slice(&0i32.to_le_bytes()); // has var sharing
+        f.extend_from_slice(&0i32.to_le_bytes()); // shared connectivity
+        f.extend_from_slice(&1.0f64.to_le_bytes()); // min
+        f.extend_from_slice(&2.0f64.to_le_bytes()); // max
+        f.extend_from_slice(&1.0f32.to_le_bytes());
+        f.extend_from_slice(&2.0f32.to_le_bytes());
+
+        let hdr = read_header(&f).unwrap();
+        assert_eq!(hdr.zone_markers.len(), 1);
+        let zones = read_data(&f, &hdr).unwrap();
+        assert_eq!(zones.len(), 1);
+        assert_eq!(zones[0].name, "Z");
+        assert_eq!(zones[0].dims, [2, 1, 1]);
+        assert_eq!(zones[0].var_min, vec![1.0]);
+        assert_eq!(zones[0].var_max, vec![2.0]);
+        assert_eq!(zones[0].data, vec![VarData::F32(vec![1.0, 2.0])]);
+    }
+
+    #[test]
+    fn test_bad_magic() {
+        let bytes = b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00";
+        assert!(matches!(read_header(bytes), Err(PltError::BadMagic)));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut f: Vec<u8> = Vec::new();
+        f.extend_from_slice(b"#!TDV112");
+        f.extend_from_slice(&1i32.to_le_bytes()); // byte order
+        f.extend_from_slice(&0i32.to_le_bytes()); // file type FULL
+        f.extend_from_slice(&(b'x' as i32).to_le_bytes()); // title "x"
+        f.extend_from_slice(&0i32.to_le_bytes());
+        f.extend_from_slice(&1i32.to_le_bytes()); // num vars
+        f.extend_from_slice(&(b'V' as i32).to_le_bytes()); // var name "V"
+        f.extend_from_slice(&0i32.to_le_bytes());
+        // zone header record
+        f.extend_from_slice(&299.0f32.to_le_bytes());
+        f.extend_from_slice(&(b'Z' as i32).to_le_bytes()); // zone name "Z"
+        f.extend_from_slice(&0i32.to_le_bytes());
+        f.extend_from_slice(&(-1i32).to_le_bytes()); // parent zone
+        f.extend_from_slice(&3i32.to_le_bytes()); // strand id (non-zero)
+        f.extend_from_slice(&2.5f64.to_le_bytes()); // solution time (non-zero)
+        f.extend_from_slice(&(-1i32).to_le_bytes()); // not used
+        f.extend_from_slice(&0i32.to_le_bytes()); // zone type
+        f.extend_from_slice(&0i32.to_le_bytes()); // var location
+        f.extend_from_slice(&0i32.to_le_bytes()); // raw face neighbours
+        f.extend_from_slice(&0i32.to_le_bytes()); // user face connections
+        f.extend_from_slice(&2i32.to_le_bytes()); // IMax
+        f.extend_from_slice(&1i32.to_le_bytes()); // JMax
+        f.extend_from_slice(&1i32.to_le_bytes()); // KMax
+        f.extend_from_slice(&357.0f32.to_le_bytes()); // end of header
+        // data section
+        f.extend_from_slice(&299.0f32.to_le_bytes());
+        f.extend_from_slice(&1i32.to_le_bytes()); // data format f32
+        f.extend_from_slice(&0i32.to_le_bytes()); // has passive
+        f.extend_from_slice(&0i32.to_le_bytes()); // has var sharing
+        f.extend_from_slice(&(-1i32).to_le_bytes()); // shared connectivity
+        f.extend_from_slice(&1.0f64.to_le_bytes()); // min
+        f.extend_from_slice(&2.0f64.to_le_bytes()); // max
+        f.extend_from_slice(&1.0f32.to_le_bytes());
+        f.extend_from_slice(&2.0f32.to_le_bytes());
+
+        let hdr = read_header(&f).unwrap();
+        let zones = read_data(&f, &hdr).unwrap();
+        assert_eq!(zones[0].strand, 3);
+        assert_eq!(zones[0].solution_time, 2.5);
+        let mut out: Vec<u8> = Vec::new();
+        write_plt(&mut out, &hdr, &zones).unwrap();
+        assert_eq!(out, f);
+    }
+
+    #[test]
+    fn test_negative_num_vars() {
+        let mut f: Vec<u8> = Vec::new();
+        f.extend_from_slice(b"#!TDV112");
+        f.extend_from_slice(&1i32.to_le_bytes()); // byte order
+        f.extend_from_slice(&0i32.to_le_bytes()); // file type FULL
+        f.extend_from_slice(&(b'x' as i32).to_le_bytes()); // title "x"
+        f.extend_from_slice(&0i32.to_le_bytes());
+        f.extend_from_slice(&(-1i32).to_le_bytes()); // num vars
+        assert!(matches!(
+            read_header(&f),
+            Err(PltError::InvalidVarCount(-1))
+        ));
+    }
+
+    #[test]
+    fn test_detect_big_endian() {
+        let bytes = b"\x23\x21\x54\x44\x56\x31\x31\x32\x00\x00\x00\x01";
+        assert_eq!(detect_endian(bytes), Some(Endian::Big));
+    }
+
+    #[test]
+    fn test_read_big_endian() {
+        let mut f: Vec<u8> = Vec::new();
+        f.extend_from_slice(b"#!TDV112");
+        f.extend_from_slice(&1i32.to_be_bytes()); // byte order
+        f.extend_from_slice(&0i32.to_be_bytes()); // file type FULL
+        f.extend_from_slice(&(b'x' as i32).to_be_bytes()); // title "x"
+        f.extend_from_slice(&0i32.to_be_bytes());
+        f.extend_from_slice(&1i32.to_be_bytes()); // num vars
+        f.extend_from_slice(&(b'V' as i32).to_be_bytes()); // var name "V"
+        f.extend_from_slice(&0i32.to_be_bytes());
+        // zone header record
+        f.extend_from_slice(&299.0f32.to_be_bytes());
+        f.extend_from_slice(&(b'Z' as i32).to_be_bytes()); // zone name "Z"
+        f.extend_from_slice(&0i32.to_be_bytes());
+        f.extend_from_slice(&(-1i32).to_be_bytes()); // parent zone
+        f.extend_from_slice(&(-1i32).to_be_bytes()); // strand id
+        f.extend_from_slice(&0f64.to_be_bytes()); // solution time
+        f.extend_from_slice(&(-1i32).to_be_bytes()); // not used
+        f.extend_from_slice(&0i32.to_be_bytes()); // zone type
+        f.extend_from_slice(&0i32.to_be_bytes()); // var location
+        f.extend_from_slice(&0i32.to_be_bytes()); // raw face neighbours
+        f.extend_from_slice(&0i32.to_be_bytes()); // user face connections
+        f.extend_from_slice(&2i32.to_be_bytes()); // IMax
+        f.extend_from_slice(&1i32.to_be_bytes()); // JMax
+        f.extend_from_slice(&1i32.to_be_bytes()); // KMax
+        f.extend_from_slice(&357.0f32.to_be_bytes()); // end of header
+        // data section
+        f.extend_from_slice(&299.0f32.to_be_bytes());
+        f.extend_from_slice(&1i32.to_be_bytes()); // data format f32
+        f.extend_from_slice(&0i32.to_be_bytes()); // has passive
+        f.extend_from_slice(&0i32.to_be_bytes()); // has var sharing
+        f.extend_from_slice(&0i32.to_be_bytes()); // shared connectivity
+        f.extend_from_slice(&1.0f64.to_be_bytes()); // min
+        f.extend_from_slice(&2.0f64.to_be_bytes()); // max
+        f.extend_from_slice(&1.0f32.to_be_bytes());
+        f.extend_from_slice(&2.0f32.to_be_bytes());
+
+        let hdr = read_header(&f).unwrap();
+        assert_eq!(hdr.endian, Endian::Big);
+        assert_eq!(hdr.file_type, "FULL");
+        assert_eq!(hdr.title, "x");
+        assert_eq!(hdr.num_vars, 1);
+        let zones = read_data(&f, &hdr).unwrap();
+        assert_eq!(zones.len(), 1);
+        assert_eq!(zones[0].dims, [2, 1, 1]);
+        assert_eq!(zones[0].var_min, vec![1.0]);
+        assert_eq!(zones[0].data, vec![VarData::F32(vec![1.0, 2.0])]);
+    }
 }